@@ -34,6 +34,11 @@ impl Polygon {
                 "A polygon should have at least 3 vertices.",
             ));
         }
+        if vertices.iter().any(|v| !v.is_finite()) {
+            return Err(PyValueError::new_err(
+                "Vertices must be finite numbers (not NaN or infinite).",
+            ));
+        }
         Ok(())
     }
 }
@@ -55,6 +60,25 @@ impl Polygon {
         Ok(Polygon { vertices })
     }
 
+    /// Builds the convex hull of an arbitrary set of points.
+    ///
+    /// Arguments
+    /// ---------
+    /// * `points` - A flat `list` of `float` values representing the (x, y)
+    ///   points to build the hull from.
+    ///
+    /// Returns
+    /// -------
+    /// * A `Polygon` object describing the hull in counter-clockwise order,
+    ///   or a `ValueError` if the hull has fewer than 3 vertices.
+    #[staticmethod]
+    fn convex_hull(points: Vec<f64>) -> PyResult<Self> {
+        Self::validate_vertices(&points)?;
+        let vertices = convex_hull_internal(&points);
+        Self::validate_vertices(&vertices)?;
+        Ok(Polygon { vertices })
+    }
+
     /// Sets the vertices of the polygon.
     ///
     /// Arguments
@@ -84,7 +108,9 @@ impl Polygon {
     ///
     /// Arguments
     /// ---------
-    /// * `area` - An optional `float` representing the area of the polygon. If `None`, the area will be calculated.
+    /// * `area` - An optional `float` representing the signed area of the
+    ///   polygon (positive if wound counter-clockwise, negative if
+    ///   clockwise). If `None`, the signed area will be calculated.
     ///
     /// Returns
     /// -------
@@ -92,127 +118,648 @@ impl Polygon {
     fn centroid(&self, area: Option<f64>) -> PyResult<(f64, f64)> {
         Ok(polygon_centroid_internal(&self.vertices, area))
     }
+
+    /// Calculates and returns the second moments of area of the polygon
+    /// about its centroid.
+    ///
+    /// Returns
+    /// -------
+    /// * A `tuple` of `float` values `(Ix, Iy, Ixy)` representing the area
+    ///   moments of inertia about the centroid.
+    fn moments(&self) -> PyResult<(f64, f64, f64)> {
+        Ok(polygon_moments_internal(&self.vertices))
+    }
+
+    /// Checks whether the polygon is convex.
+    ///
+    /// Returns
+    /// -------
+    /// * `true` if the polygon is convex, `false` otherwise.
+    fn is_convex(&self) -> bool {
+        is_convex_internal(&self.vertices)
+    }
+
+    /// Checks whether a point lies inside the polygon, using the
+    /// crossing-number (ray casting) algorithm. Works for concave polygons.
+    ///
+    /// Arguments
+    /// ---------
+    /// * `x` - The `x` coordinate of the test point.
+    /// * `y` - The `y` coordinate of the test point.
+    /// * `boundary_inclusive` - If `true`, a point lying exactly on an edge
+    ///   counts as inside. Defaults to `false`.
+    ///
+    /// Returns
+    /// -------
+    /// * `true` if the point lies inside the polygon, `false` otherwise.
+    fn contains(&self, x: f64, y: f64, boundary_inclusive: Option<bool>) -> bool {
+        polygon_contains_internal(&self.vertices, x, y, boundary_inclusive.unwrap_or(false))
+    }
+
+    /// Finds the vertex of the polygon that maximizes the dot product with
+    /// the direction `(dx, dy)`, via a binary search over the edges.
+    ///
+    /// The polygon must be convex and wound counter-clockwise (see
+    /// [`is_convex`](Self::is_convex) and [`convex_hull`](Self::convex_hull)).
+    ///
+    /// Arguments
+    /// ---------
+    /// * `dx` - The `x` component of the search direction.
+    /// * `dy` - The `y` component of the search direction.
+    ///
+    /// Returns
+    /// -------
+    /// * A `tuple` of `float` values representing the (x, y) coordinates of
+    ///   the supporting vertex.
+    fn support_vector(&self, dx: f64, dy: f64) -> (f64, f64) {
+        support_vector_internal(&self.vertices, dx, dy)
+    }
+
+    /// Computes the Minkowski sum of this polygon with `other`.
+    ///
+    /// Both polygons must be convex and wound counter-clockwise (see
+    /// [`is_convex`](Self::is_convex) and [`convex_hull`](Self::convex_hull)).
+    ///
+    /// Arguments
+    /// ---------
+    /// * `other` - The `Polygon` to sum with.
+    ///
+    /// Returns
+    /// -------
+    /// * A new `Polygon` representing the Minkowski sum, or a `ValueError`
+    ///   if the result is degenerate.
+    fn minkowski_sum(&self, other: &Polygon) -> PyResult<Self> {
+        let vertices = minkowski_sum_internal(&self.vertices, &other.vertices);
+        Self::validate_vertices(&vertices)?;
+        Ok(Polygon { vertices })
+    }
+
+    /// Translates the polygon by `(dx, dy)`.
+    ///
+    /// Arguments
+    /// ---------
+    /// * `dx` - The displacement along the `x` axis.
+    /// * `dy` - The displacement along the `y` axis.
+    ///
+    /// Returns
+    /// -------
+    /// * A new `Polygon` translated by `(dx, dy)`.
+    fn translate(&self, dx: f64, dy: f64) -> PyResult<Self> {
+        let vertices = translate_internal(&self.vertices, dx, dy);
+        Self::validate_vertices(&vertices)?;
+        Ok(Polygon { vertices })
+    }
+
+    /// Scales the polygon by `(sx, sy)` about `pivot`.
+    ///
+    /// Arguments
+    /// ---------
+    /// * `sx` - The scale factor along the `x` axis.
+    /// * `sy` - The scale factor along the `y` axis.
+    /// * `pivot` - The `(x, y)` point to scale about. Defaults to the origin.
+    ///
+    /// Returns
+    /// -------
+    /// * A new `Polygon` scaled by `(sx, sy)` about `pivot`.
+    fn scale(&self, sx: f64, sy: f64, pivot: Option<(f64, f64)>) -> PyResult<Self> {
+        let (px, py) = pivot.unwrap_or((0.0, 0.0));
+        let vertices = scale_internal(&self.vertices, sx, sy, px, py);
+        Self::validate_vertices(&vertices)?;
+        Ok(Polygon { vertices })
+    }
+
+    /// Rotates the polygon by `theta` radians about `pivot`.
+    ///
+    /// Arguments
+    /// ---------
+    /// * `theta` - The rotation angle, in radians.
+    /// * `pivot` - The `(x, y)` point to rotate about. Defaults to the
+    ///   origin.
+    ///
+    /// Returns
+    /// -------
+    /// * A new `Polygon` rotated by `theta` radians about `pivot`.
+    fn rotate(&self, theta: f64, pivot: Option<(f64, f64)>) -> PyResult<Self> {
+        let (px, py) = pivot.unwrap_or((0.0, 0.0));
+        let vertices = rotate_internal(&self.vertices, theta, px, py);
+        Self::validate_vertices(&vertices)?;
+        Ok(Polygon { vertices })
+    }
+}
+
+/// Represents a polygon with holes, as an outer ring plus zero or more
+/// inner (hole) rings. Each ring is a flat array of (x, y) pairs, in the
+/// same format as `Polygon`.
+#[pyclass]
+struct MultiPolygon {
+    #[pyo3(get)]
+    rings: Vec<Vec<f64>>,
+}
+
+impl MultiPolygon {
+    /// Validates the rings of a multi-polygon.
+    /// There should be at least one ring (the outer boundary), and every
+    /// ring should be a valid polygon.
+    fn validate_rings(rings: &Vec<Vec<f64>>) -> PyResult<()> {
+        if rings.is_empty() {
+            return Err(PyValueError::new_err(
+                "A MultiPolygon should have at least one (outer) ring.",
+            ));
+        }
+        for ring in rings {
+            Polygon::validate_vertices(ring)?;
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl MultiPolygon {
+    /// Creates a new `MultiPolygon` from an outer ring and any number of
+    /// hole rings.
+    ///
+    /// Arguments
+    /// ---------
+    /// * `rings` - A `list` of rings, the first being the outer boundary
+    ///   and the rest being holes, each a flat `list` of `float` vertices.
+    ///
+    /// Returns
+    /// -------
+    /// * A `MultiPolygon` object or a `ValueError` if any ring is invalid.
+    #[new]
+    fn new(rings: Vec<Vec<f64>>) -> PyResult<Self> {
+        Self::validate_rings(&rings)?;
+        Ok(MultiPolygon { rings })
+    }
+
+    /// Calculates and returns the total area of the multi-polygon: the
+    /// outer ring's area minus the area of each hole.
+    ///
+    /// Returns
+    /// -------
+    /// * A `float` representing the total area of the multi-polygon.
+    fn area(&self) -> PyResult<f64> {
+        Ok(multipolygon_area_internal(&self.rings))
+    }
+
+    /// Calculates and returns the centroid of the multi-polygon, as the
+    /// signed-area-weighted average of each ring's centroid.
+    ///
+    /// Returns
+    /// -------
+    /// * A `tuple` of `float` values representing the (x, y) coordinates of
+    ///   the centroid.
+    fn centroid(&self) -> PyResult<(f64, f64)> {
+        Ok(multipolygon_centroid_internal(&self.rings))
+    }
 }
 
 #[pymodule]
 fn polyprops(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<Polygon>()?;
+    m.add_class::<MultiPolygon>()?;
     Ok(())
 }
 
 ///
-/// Computes the centroid of a polygon given its vertices.
+/// Computes the centroid of a polygon given its vertices, using the Bourke
+/// & Nürnberg method via the signed area. Keeping the area signed (instead
+/// of taking its absolute value) makes the formula correct for any
+/// winding or quadrant, so no coordinate shifting is needed.
 ///
 /// Vertices should be provided as a flat array of (x, y) pairs.
+///
+/// If `area` is provided it is used as the denominator as-is, so it
+/// should be the *signed* area (see `polygon_signed_area_internal`) rather
+/// than the unsigned area returned by `polygon_area_internal`. If `None`,
+/// the signed area is computed from `vertices`.
 fn polygon_centroid_internal(vertices: &Vec<f64>, area: Option<f64>) -> (f64, f64) {
-    ///
-    /// Shifts all x-coordinates by dx
-    /// Assuming they are in the form [x1, y1, x2, y2, ...]
-    fn shift_x(vertices: &mut Vec<f64>, dx: f64) {
-        let n = vertices.len();
-        for i in 0..(n / 2) {
-            vertices[2 * i] += dx;
+    let area = match area {
+        Some(area) => area,
+        None => polygon_signed_area_internal(vertices),
+    };
+
+    let mut summation_x = 0.0;
+    let mut summation_y = 0.0;
+
+    let n = vertices.len();
+    for i in 0..(n / 2) {
+        let x1 = vertices[2 * i];
+        let y1 = vertices[2 * i + 1];
+        let x2 = vertices[(2 * i + 2) % n];
+        let y2 = vertices[(2 * i + 3) % n];
+
+        let c = x1 * y2 - x2 * y1;
+        summation_x += (x1 + x2) * c;
+        summation_y += (y1 + y2) * c;
+    }
+
+    (summation_x / (6.0 * area), summation_y / (6.0 * area))
+}
+
+///
+/// Computes the signed area of a polygon given its vertices using the
+/// shoelace formula. Positive for vertices wound counter-clockwise,
+/// negative for clockwise.
+///
+/// Vertices should be provided as a flat array of (x, y) pairs.
+fn polygon_signed_area_internal(vertices: &Vec<f64>) -> f64 {
+    let mut area = 0.0;
+    let n = vertices.len();
+    for i in 0..(n / 2) {
+        let x1 = vertices[2 * i];
+        let y1 = vertices[2 * i + 1];
+        let x2 = vertices[(2 * i + 2) % n];
+        let y2 = vertices[(2 * i + 3) % n];
+        area += x1 * y2 - x2 * y1;
+    }
+    area / 2.0
+}
+
+///
+/// Computes the area of a polygon given its vertices using the shoelace formula.
+///
+/// Vertices should be provided as a flat array of (x, y) pairs.
+fn polygon_area_internal(vertices: &Vec<f64>) -> f64 {
+    polygon_signed_area_internal(vertices).abs()
+}
+
+///
+/// Computes the second moments of area (Ix, Iy, Ixy) of a polygon about its
+/// centroid, using the same shoelace pass as `polygon_area_internal`.
+///
+/// Vertices should be provided as a flat array of (x, y) pairs.
+fn polygon_moments_internal(vertices: &Vec<f64>) -> (f64, f64, f64) {
+    let signed_area = polygon_signed_area_internal(vertices);
+    let (cx, cy) = polygon_centroid_internal(vertices, Some(signed_area));
+
+    let mut ix_o = 0.0;
+    let mut iy_o = 0.0;
+    let mut ixy_o = 0.0;
+
+    let n = vertices.len();
+    for i in 0..(n / 2) {
+        let x1 = vertices[2 * i];
+        let y1 = vertices[2 * i + 1];
+        let x2 = vertices[(2 * i + 2) % n];
+        let y2 = vertices[(2 * i + 3) % n];
+
+        let c = x1 * y2 - x2 * y1;
+        ix_o += (y1 * y1 + y1 * y2 + y2 * y2) * c;
+        iy_o += (x1 * x1 + x1 * x2 + x2 * x2) * c;
+        ixy_o += (x1 * y2 + 2.0 * x1 * y1 + 2.0 * x2 * y2 + x2 * y1) * c;
+    }
+
+    ix_o /= 12.0;
+    iy_o /= 12.0;
+    ixy_o /= 24.0;
+
+    // ix_o/iy_o/ixy_o carry the same winding-dependent sign as signed_area
+    // (both come from the same `c` factor), so normalizing everything by
+    // that sign keeps the moments about the origin independent of winding
+    // before the parallel-axis shift is applied.
+    let sign = signed_area.signum();
+    let area = signed_area * sign;
+    let ix_o = ix_o * sign;
+    let iy_o = iy_o * sign;
+    let ixy_o = ixy_o * sign;
+
+    // Parallel-axis shift from the origin to the centroid.
+    let ix = ix_o - area * cy * cy;
+    let iy = iy_o - area * cx * cx;
+    let ixy = ixy_o - area * cx * cy;
+
+    (ix, iy, ixy)
+}
+
+///
+/// Builds the convex hull of a set of points using Andrew's monotone chain
+/// algorithm, in O(n log n).
+///
+/// Points should be provided as a flat array of (x, y) pairs. Returns a new
+/// flat array of (x, y) pairs describing the hull in counter-clockwise
+/// order.
+fn convex_hull_internal(points: &Vec<f64>) -> Vec<f64> {
+    /// Cross product of `(a - o)` and `(b - o)`.
+    fn cross(o: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    }
+
+    let mut pts: Vec<(f64, f64)> = (0..(points.len() / 2))
+        .map(|i| (points[2 * i], points[2 * i + 1]))
+        .collect();
+    pts.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    pts.dedup();
+
+    if pts.len() < 3 {
+        return pts.into_iter().flat_map(|(x, y)| vec![x, y]).collect();
+    }
+
+    let mut lower: Vec<(f64, f64)> = Vec::new();
+    for &p in &pts {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0 {
+            lower.pop();
         }
+        lower.push(p);
     }
 
-    ///
-    /// Shifts all y-coordinates by dy
-    /// Assuming they are in the form [x1, y1, x2, y2, ...]
-    fn shift_y(vertices: &mut Vec<f64>, dy: f64) {
-        let n = vertices.len();
-        for i in 0..(n / 2) {
-            vertices[2 * i + 1] += dy;
+    let mut upper: Vec<(f64, f64)> = Vec::new();
+    for &p in pts.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0 {
+            upper.pop();
         }
+        upper.push(p);
     }
 
-    ///
-    /// Computes the centroid of a polygon given its vertices using the Bourke & Nürnberg method.
-    /// Does not allow negative vertices.
-    fn centroid_algorithm(vertices: &Vec<f64>, area: Option<f64>) -> Result<(f64, f64), PyErr> {
-        // If the area is not provided, compute it.
-        let area = match area {
-            Some(area) => area,
-            None => polygon_area_internal(&vertices),
-        };
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
 
-        // Calculate X and Y coordinates of the centroid using the Bourke & Nürnberg method
-        let mut summation_x = 0.0;
-        let mut summation_y = 0.0;
+    lower.into_iter().flat_map(|(x, y)| vec![x, y]).collect()
+}
 
-        let n = vertices.len();
-        for i in 0..(n / 2) {
-            let x1 = vertices[2 * i];
-            let y1 = vertices[2 * i + 1];
-            let x2 = vertices[(2 * i + 2) % n];
-            let y2 = vertices[(2 * i + 3) % n];
-
-            // Check if any of the vertices are negative. The Bourke & Nürnberg method does not work
-            // for polygons with negative vertices.
-            if x1 < 0.0 || x2 < 0.0 || y1 < 0.0 || y2 < 0.0 {
-                Err(PyValueError::new_err("Polygon contains negative vertices."))?;
-            }
+///
+/// Checks whether a polygon is convex by verifying that the cross product
+/// of consecutive edges never changes sign.
+///
+/// Vertices should be provided as a flat array of (x, y) pairs.
+fn is_convex_internal(vertices: &Vec<f64>) -> bool {
+    let n = vertices.len() / 2;
+    let mut sign = 0.0;
+
+    for i in 0..n {
+        let x1 = vertices[2 * i];
+        let y1 = vertices[2 * i + 1];
+        let x2 = vertices[(2 * i + 2) % (2 * n)];
+        let y2 = vertices[(2 * i + 3) % (2 * n)];
+        let x3 = vertices[(2 * i + 4) % (2 * n)];
+        let y3 = vertices[(2 * i + 5) % (2 * n)];
 
-            summation_x += (x1 + x2) * (x1 * y2 - x2 * y1);
-            summation_y += (y1 + y2) * (x1 * y2 - x2 * y1);
+        let cross = (x2 - x1) * (y3 - y2) - (y2 - y1) * (x3 - x2);
+        if cross != 0.0 {
+            if sign == 0.0 {
+                sign = cross.signum();
+            } else if cross.signum() != sign {
+                return false;
+            }
         }
+    }
+
+    true
+}
 
-        let centroid_x = (summation_x / (6.0 * area)).abs();
-        let centroid_y = (summation_y / (6.0 * area)).abs();
+///
+/// Checks whether the point `(px, py)` lies on the segment
+/// `(x1, y1)-(x2, y2)`.
+///
+/// The cross-product tolerance is scaled by the edge's squared length
+/// rather than fixed, so the test stays accurate for edges at any
+/// coordinate magnitude (e.g. projected/UTM coordinates).
+fn on_segment(x1: f64, y1: f64, x2: f64, y2: f64, px: f64, py: f64) -> bool {
+    const EPS: f64 = 1e-9;
 
-        Ok((centroid_x, centroid_y))
+    let cross = (x2 - x1) * (py - y1) - (y2 - y1) * (px - x1);
+    let edge_length_sq = (x2 - x1) * (x2 - x1) + (y2 - y1) * (y2 - y1);
+    if cross * cross > EPS * edge_length_sq {
+        return false;
     }
+    let dot = (px - x1) * (px - x2) + (py - y1) * (py - y2);
+    dot <= 0.0
+}
 
-    // Find the minimum x and y-coordinates of the polygon.
-    // If they are negative, shift the polygon by the absolute value of the minimum
-    // so that all vertices are positive.
+///
+/// Checks whether a point lies inside a polygon using the crossing-number
+/// (ray casting) algorithm: casts a ray to +x and counts edge crossings
+/// where one endpoint is strictly above and the other at-or-below the test
+/// `y`.
+///
+/// Vertices should be provided as a flat array of (x, y) pairs.
+fn polygon_contains_internal(
+    vertices: &Vec<f64>,
+    x: f64,
+    y: f64,
+    boundary_inclusive: bool,
+) -> bool {
+    let n = vertices.len() / 2;
+    let mut inside = false;
 
-    let mut min_x = vertices[0];
-    let mut min_y = vertices[1];
+    for i in 0..n {
+        let x1 = vertices[2 * i];
+        let y1 = vertices[2 * i + 1];
+        let x2 = vertices[(2 * i + 2) % (2 * n)];
+        let y2 = vertices[(2 * i + 3) % (2 * n)];
 
-    for i in 0..(vertices.len() / 2) {
-        let x = vertices[2 * i];
-        let y = vertices[2 * i + 1];
-        if x < min_x {
-            min_x = x;
+        if boundary_inclusive && on_segment(x1, y1, x2, y2, x, y) {
+            return true;
         }
-        if y < min_y {
-            min_y = y;
+
+        if (y1 > y) != (y2 > y) {
+            let x_intersect = x1 + (y - y1) / (y2 - y1) * (x2 - x1);
+            if x < x_intersect {
+                inside = !inside;
+            }
         }
     }
 
-    if min_x < 0.0 || min_y < 0.0 {
-        let mut shifted_vertices = vertices.to_owned();
-        if min_x < 0.0 {
-            shift_x(&mut shifted_vertices, min_x.abs());
+    inside
+}
+
+///
+/// Finds the vertex of a convex, counter-clockwise polygon that maximizes
+/// the dot product with the direction `(dx, dy)`. The dot product is
+/// unimodal around a convex hull, so a binary search finds it in O(log n)
+/// instead of O(n).
+///
+/// Vertices should be provided as a flat array of (x, y) pairs.
+fn support_vector_internal(vertices: &Vec<f64>, dx: f64, dy: f64) -> (f64, f64) {
+    let n = vertices.len() / 2;
+
+    let dot = |i: usize| -> f64 {
+        let i = i % n;
+        vertices[2 * i] * dx + vertices[2 * i + 1] * dy
+    };
+
+    if dot(0) >= dot(1) && dot(0) > dot(n - 1) {
+        return (vertices[0], vertices[1]);
+    }
+
+    // Every index before the first maximum is on the run rising from
+    // vertex 0, or, if the dot product falls from vertex 0, either below
+    // vertex 0 or on the run rising from the minimum.
+    let rising = |i: usize| dot(i + 1) > dot(i);
+    let starts_rising = rising(0);
+    let before_max = |i: usize| {
+        if starts_rising {
+            rising(i) && dot(i) >= dot(0)
+        } else {
+            rising(i) || dot(i) < dot(0)
         }
-        if min_y < 0.0 {
-            shift_y(&mut shifted_vertices, min_y.abs());
+    };
+
+    let mut lo = 1;
+    let mut hi = n;
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if before_max(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
         }
+    }
 
-        // Compute the centroid of the shifted polygon. Then shift the centroid back.
-        let (x, y) = centroid_algorithm(&shifted_vertices, area).unwrap();
-        return (x - min_x.abs(), y - min_y.abs());
-    } else {
-        return centroid_algorithm(&vertices, area).unwrap();
+    (vertices[2 * lo], vertices[2 * lo + 1])
+}
+
+///
+/// Computes the Minkowski sum of two convex, counter-clockwise polygons by
+/// walking both hulls in lockstep, at each step advancing whichever
+/// polygon's outgoing edge has the smaller polar angle.
+///
+/// Vertices should be provided as flat arrays of (x, y) pairs.
+fn minkowski_sum_internal(a: &Vec<f64>, b: &Vec<f64>) -> Vec<f64> {
+    /// Finds the index of the lowest, then leftmost, vertex.
+    fn lowest_leftmost(v: &Vec<f64>, n: usize) -> usize {
+        let mut best = 0;
+        for i in 1..n {
+            if (v[2 * i + 1], v[2 * i]) < (v[2 * best + 1], v[2 * best]) {
+                best = i;
+            }
+        }
+        best
     }
+
+    let na = a.len() / 2;
+    let nb = b.len() / 2;
+    let ia0 = lowest_leftmost(a, na);
+    let ib0 = lowest_leftmost(b, nb);
+
+    let mut result = Vec::new();
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < na || j < nb {
+        let ia = (ia0 + i) % na;
+        let ib = (ib0 + j) % nb;
+
+        result.push(a[2 * ia] + b[2 * ib]);
+        result.push(a[2 * ia + 1] + b[2 * ib + 1]);
+
+        if i >= na {
+            j += 1;
+            continue;
+        }
+        if j >= nb {
+            i += 1;
+            continue;
+        }
+
+        let ia_next = (ia0 + i + 1) % na;
+        let ib_next = (ib0 + j + 1) % nb;
+
+        let edge_a = (
+            a[2 * ia_next] - a[2 * ia],
+            a[2 * ia_next + 1] - a[2 * ia + 1],
+        );
+        let edge_b = (
+            b[2 * ib_next] - b[2 * ib],
+            b[2 * ib_next + 1] - b[2 * ib + 1],
+        );
+
+        let cross = edge_a.0 * edge_b.1 - edge_a.1 * edge_b.0;
+        if cross > 0.0 {
+            i += 1;
+        } else if cross < 0.0 {
+            j += 1;
+        } else {
+            i += 1;
+            j += 1;
+        }
+    }
+
+    result
 }
 
 ///
-/// Computes the area of a polygon given its vertices using the shoelace formula.
+/// Translates a flat array of (x, y) pairs by `(dx, dy)`.
+fn translate_internal(vertices: &Vec<f64>, dx: f64, dy: f64) -> Vec<f64> {
+    let mut result = vertices.clone();
+    let n = result.len() / 2;
+    for i in 0..n {
+        result[2 * i] += dx;
+        result[2 * i + 1] += dy;
+    }
+    result
+}
+
 ///
-/// Vertices should be provided as a flat array of (x, y) pairs.
-fn polygon_area_internal(vertices: &Vec<f64>) -> f64 {
-    // Compute the area using the shoelace formula.
-    let mut area = 0.0;
-    let n = vertices.len();
-    for i in 0..(n / 2) {
-        let x1 = vertices[2 * i];
-        let y1 = vertices[2 * i + 1];
-        let x2 = vertices[(2 * i + 2) % n];
-        let y2 = vertices[(2 * i + 3) % n];
-        area += x1 * y2 - x2 * y1;
+/// Scales a flat array of (x, y) pairs by `(sx, sy)` about the pivot
+/// `(px, py)`.
+fn scale_internal(vertices: &Vec<f64>, sx: f64, sy: f64, px: f64, py: f64) -> Vec<f64> {
+    let mut result = vertices.clone();
+    let n = result.len() / 2;
+    for i in 0..n {
+        result[2 * i] = px + (result[2 * i] - px) * sx;
+        result[2 * i + 1] = py + (result[2 * i + 1] - py) * sy;
+    }
+    result
+}
+
+///
+/// Rotates a flat array of (x, y) pairs by `theta` radians about the pivot
+/// `(px, py)`.
+fn rotate_internal(vertices: &Vec<f64>, theta: f64, px: f64, py: f64) -> Vec<f64> {
+    let mut result = vertices.clone();
+    let n = result.len() / 2;
+    let (sin_t, cos_t) = theta.sin_cos();
+    for i in 0..n {
+        let x = vertices[2 * i];
+        let y = vertices[2 * i + 1];
+        result[2 * i] = px + (x - px) * cos_t - (y - py) * sin_t;
+        result[2 * i + 1] = py + (x - px) * sin_t + (y - py) * cos_t;
+    }
+    result
+}
+
+///
+/// Computes the total area of a multi-ring polygon: the outer ring's area
+/// minus the area of each hole.
+///
+/// `rings[0]` is the outer ring, and `rings[1..]` are hole rings.
+fn multipolygon_area_internal(rings: &Vec<Vec<f64>>) -> f64 {
+    let outer = polygon_signed_area_internal(&rings[0]).abs();
+    let holes: f64 = rings[1..]
+        .iter()
+        .map(|ring| polygon_signed_area_internal(ring).abs())
+        .sum();
+    outer - holes
+}
+
+///
+/// Computes the centroid of a multi-ring polygon as the signed-area-weighted
+/// average of each ring's centroid, `C = Σ(A_k·C_k) / ΣA_k`, so that holes
+/// subtract their contribution.
+///
+/// `rings[0]` is the outer ring, and `rings[1..]` are hole rings.
+fn multipolygon_centroid_internal(rings: &Vec<Vec<f64>>) -> (f64, f64) {
+    let mut total_area = 0.0;
+    let mut cx = 0.0;
+    let mut cy = 0.0;
+
+    for (i, ring) in rings.iter().enumerate() {
+        let signed_area = polygon_signed_area_internal(ring);
+        let signed_ring_area = if i == 0 {
+            signed_area.abs()
+        } else {
+            -signed_area.abs()
+        };
+        let (rx, ry) = polygon_centroid_internal(ring, Some(signed_area));
+
+        total_area += signed_ring_area;
+        cx += signed_ring_area * rx;
+        cy += signed_ring_area * ry;
     }
-    area.abs() / 2.0
+
+    (cx / total_area, cy / total_area)
 }
 
 #[cfg(test)]
@@ -253,4 +800,121 @@ mod tests {
         let vertices = vec![0.0, 0.0, 1.0, 0.0, 1.0, -1.0];
         assert!(isclose(polygon_area_internal(&vertices), 0.5));
     }
+
+    #[test]
+    fn test_polygon_moments_unit_square() {
+        let vertices = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let (ix, iy, ixy) = polygon_moments_internal(&vertices);
+        assert!(isclose(ix, 1.0 / 12.0));
+        assert!(isclose(iy, 1.0 / 12.0));
+        assert!(isclose(ixy, 0.0));
+    }
+
+    #[test]
+    fn test_polygon_moments_unit_square_cw() {
+        let vertices = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+        let (ix, iy, ixy) = polygon_moments_internal(&vertices);
+        assert!(isclose(ix, 1.0 / 12.0));
+        assert!(isclose(iy, 1.0 / 12.0));
+        assert!(isclose(ixy, 0.0));
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let points = vec![0.0, 0.0, 2.0, 0.0, 1.0, 1.0, 2.0, 2.0, 0.0, 2.0];
+        let hull = convex_hull_internal(&points);
+        assert_eq!(hull, vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_convex_hull_rejects_non_finite_points() {
+        let points = vec![0.0, 0.0, 2.0, 0.0, 1.0, 1.0, 2.0, 2.0, f64::NAN, f64::NAN];
+        assert!(Polygon::convex_hull(points).is_err());
+    }
+
+    #[test]
+    fn test_is_convex() {
+        let square = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        assert!(is_convex_internal(&square));
+
+        let concave = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 1.0, 1.0, 0.0, 2.0];
+        assert!(!is_convex_internal(&concave));
+    }
+
+    #[test]
+    fn test_polygon_contains() {
+        let square = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+        assert!(polygon_contains_internal(&square, 1.0, 1.0, false));
+        assert!(!polygon_contains_internal(&square, 3.0, 1.0, false));
+    }
+
+    #[test]
+    fn test_polygon_contains_boundary() {
+        let square = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+        assert!(!polygon_contains_internal(&square, 2.0, 1.0, false));
+        assert!(polygon_contains_internal(&square, 2.0, 1.0, true));
+    }
+
+    #[test]
+    fn test_on_segment_large_coordinates() {
+        let x1 = 123.456;
+        let y1 = 987.654;
+        let x2 = 1.0e10 + 987.65;
+        let y2 = 3.0e9 + 111.11;
+        let t = 0.37246;
+        let px = x1 + t * (x2 - x1);
+        let py = y1 + t * (y2 - y1);
+        assert!(on_segment(x1, y1, x2, y2, px, py));
+    }
+
+    #[test]
+    fn test_support_vector() {
+        let square = vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0];
+        assert_eq!(support_vector_internal(&square, 1.0, 0.0), (2.0, 0.0));
+        assert_eq!(support_vector_internal(&square, 0.0, 1.0), (2.0, 2.0));
+        assert_eq!(support_vector_internal(&square, -1.0, 0.0), (0.0, 2.0));
+    }
+
+    #[test]
+    fn test_minkowski_sum() {
+        let a = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let b = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+        let sum = minkowski_sum_internal(&a, &b);
+        assert!(isclose(polygon_area_internal(&sum), 4.0));
+    }
+
+    #[test]
+    fn test_translate() {
+        let vertices = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+        let translated = translate_internal(&vertices, 2.0, 3.0);
+        assert_eq!(translated, vec![2.0, 3.0, 3.0, 3.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_scale() {
+        let vertices = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+        let scaled = scale_internal(&vertices, 2.0, 2.0, 0.0, 0.0);
+        assert_eq!(scaled, vec![0.0, 0.0, 2.0, 0.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let vertices = vec![1.0, 0.0];
+        let rotated = rotate_internal(&vertices, std::f64::consts::FRAC_PI_2, 0.0, 0.0);
+        assert!(isclose(rotated[0], 0.0));
+        assert!(isclose(rotated[1], 1.0));
+    }
+
+    #[test]
+    fn test_multipolygon_area_and_centroid() {
+        let outer = vec![0.0, 0.0, 4.0, 0.0, 4.0, 4.0, 0.0, 4.0];
+        let hole = vec![1.0, 1.0, 3.0, 1.0, 3.0, 3.0, 1.0, 3.0];
+        let rings = vec![outer, hole];
+
+        assert!(isclose(multipolygon_area_internal(&rings), 12.0));
+
+        let (cx, cy) = multipolygon_centroid_internal(&rings);
+        assert!(isclose(cx, 2.0));
+        assert!(isclose(cy, 2.0));
+    }
 }